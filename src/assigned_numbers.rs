@@ -0,0 +1,75 @@
+//! Lookup from [`MajorServiceClass`] capability bits to the Bluetooth SIG 16-bit service-class
+//! UUIDs that advertise them, for hosts building an SDP record or advertisement.
+//!
+//! [`MajorServiceClass`]: crate::MajorServiceClass
+
+use crate::MajorServiceClass;
+
+/// Return the Bluetooth SIG 16-bit service-class UUIDs and human-readable names for the
+/// capability bits set in `major_service_class`, analogous to Fuchsia's `find_service_uuid`.
+/// The limited discoverable mode, LE audio and capturing bits have no entry and yield nothing.
+pub fn find_service_uuids(
+    major_service_class: MajorServiceClass,
+) -> impl Iterator<Item = (u16, &'static str)> {
+    [
+        (major_service_class.networking, 0x1201, "GenericNetworking"),
+        (major_service_class.rendering, 0x1118, "DirectPrinting"),
+        (major_service_class.object_transfer, 0x1202, "GenericFileTransfer"),
+        (major_service_class.audio, 0x1203, "GenericAudio"),
+        (major_service_class.telephony, 0x1204, "GenericTelephony"),
+        (major_service_class.positioning, 0x1136, "GNSSServer"),
+        (major_service_class.information, 0x1200, "PnPInformation"),
+    ]
+    .into_iter()
+    .filter(|(set, _, _)| *set)
+    .map(|(_, uuid, name)| (uuid, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_service_uuids_is_empty_for_no_capabilities() {
+        assert_eq!(find_service_uuids(MajorServiceClass::empty()).next(), None);
+    }
+
+    #[test]
+    fn find_service_uuids_maps_each_capability_bit_individually() {
+        let cases = [
+            (
+                MajorServiceClass { networking: true, ..MajorServiceClass::empty() },
+                (0x1201, "GenericNetworking"),
+            ),
+            (
+                MajorServiceClass { rendering: true, ..MajorServiceClass::empty() },
+                (0x1118, "DirectPrinting"),
+            ),
+            (
+                MajorServiceClass { object_transfer: true, ..MajorServiceClass::empty() },
+                (0x1202, "GenericFileTransfer"),
+            ),
+            (
+                MajorServiceClass { audio: true, ..MajorServiceClass::empty() },
+                (0x1203, "GenericAudio"),
+            ),
+            (
+                MajorServiceClass { telephony: true, ..MajorServiceClass::empty() },
+                (0x1204, "GenericTelephony"),
+            ),
+            (
+                MajorServiceClass { positioning: true, ..MajorServiceClass::empty() },
+                (0x1136, "GNSSServer"),
+            ),
+            (
+                MajorServiceClass { information: true, ..MajorServiceClass::empty() },
+                (0x1200, "PnPInformation"),
+            ),
+        ];
+
+        for (major_service_class, expected) in cases {
+            let uuids: Vec<_> = find_service_uuids(major_service_class).collect();
+            assert_eq!(uuids, vec![expected]);
+        }
+    }
+}
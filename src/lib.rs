@@ -1,4 +1,11 @@
-/// Module for creating device class u32s by name.
+//! Module for creating device class u32s by name.
+//!
+//! Enable the `serde` feature to derive `Serialize`/`Deserialize` on the service and device
+//! class types and [`ClassOfDevice`], so a device class can be carried through a config file or
+//! IPC message. The error types, `DeviceClassKind`, `ParsedClassOfDevice` and
+//! `ClassOfDeviceBuilder` do not implement them.
+
+pub mod assigned_numbers;
 
 // Major and minor device class traits
 
@@ -26,6 +33,7 @@ impl <T: MajorDeviceClass + MinorDeviceClass> DeviceClass for T {
 
 // Major service class
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct MajorServiceClass {
     pub limited_discoverable_mode: bool,
@@ -58,6 +66,7 @@ impl MajorServiceClass {
     }
     
     /// Convert this MajorServiceClass into a masked u32.
+    #[allow(clippy::identity_op)]
     pub fn major_service_class(&self) -> u32 {
         0
             | if self.limited_discoverable_mode { 1 << 13 } else { 0 }
@@ -73,6 +82,59 @@ impl MajorServiceClass {
     }
 }
 
+impl std::fmt::Display for MajorServiceClass {
+    /// Format this MajorServiceClass as its set capability names, joined by `", "`, or
+    /// `"none"` if no capabilities are set.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut capabilities = Vec::new();
+        if self.limited_discoverable_mode { capabilities.push("limited discoverable mode"); }
+        if self.le_audio { capabilities.push("le audio"); }
+        if self.positioning { capabilities.push("positioning"); }
+        if self.networking { capabilities.push("networking"); }
+        if self.rendering { capabilities.push("rendering"); }
+        if self.capturing { capabilities.push("capturing"); }
+        if self.object_transfer { capabilities.push("object transfer"); }
+        if self.audio { capabilities.push("audio"); }
+        if self.telephony { capabilities.push("telephony"); }
+        if self.information { capabilities.push("information"); }
+
+        if capabilities.is_empty() {
+            write!(f, "none")
+        } else {
+            write!(f, "{}", capabilities.join(", "))
+        }
+    }
+}
+
+impl std::str::FromStr for MajorServiceClass {
+    type Err = ParseClassNameError;
+
+    /// Parse a MajorServiceClass from a `", "`-separated list of capability names, as produced
+    /// by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "none" {
+            return Ok(Self::empty());
+        }
+
+        let mut service_class = Self::empty();
+        for part in s.split(',').map(str::trim) {
+            match part {
+                "limited discoverable mode" => service_class.limited_discoverable_mode = true,
+                "le audio" => service_class.le_audio = true,
+                "positioning" => service_class.positioning = true,
+                "networking" => service_class.networking = true,
+                "rendering" => service_class.rendering = true,
+                "capturing" => service_class.capturing = true,
+                "object transfer" => service_class.object_transfer = true,
+                "audio" => service_class.audio = true,
+                "telephony" => service_class.telephony = true,
+                "information" => service_class.information = true,
+                _ => return Err(ParseClassNameError(s.to_string())),
+            }
+        }
+        Ok(service_class)
+    }
+}
 
 /// Create a u32 representing a class of device from a service class and device class.
 pub fn make_class_of_device<C: DeviceClass>(
@@ -82,9 +144,348 @@ pub fn make_class_of_device<C: DeviceClass>(
     major_service_class.major_service_class() | device_class.device_class()
 }
 
+/// Error returned by [`parse_class_of_device`] when a value cannot be parsed as a class of
+/// device.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseClassOfDeviceError {
+    /// Bits 0-1 were not `00`, so the value is not in the Bluetooth class of device format.
+    InvalidFormatType,
+}
+
+impl std::fmt::Display for ParseClassOfDeviceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidFormatType => write!(f, "class of device has an invalid format type"),
+        }
+    }
+}
+
+impl std::error::Error for ParseClassOfDeviceError {}
+
+/// Error returned when a class name does not match any of the canonical identifiers accepted
+/// by a type's `FromStr` implementation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseClassNameError(String);
+
+impl std::fmt::Display for ParseClassNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unrecognized class name: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseClassNameError {}
+
+/// The major device class and minor device class recovered from a class of device, typed
+/// according to which major category the value falls into.
+///
+/// Note that this crate's `major_device_class()` used to return the same major class code
+/// (`0x05`) for both [`AudioVideo`] and [`Peripheral`]; [`AudioVideo`] now uses `0x04`
+/// (matching the Bluetooth Assigned Numbers document), so every major class code below
+/// round-trips to exactly one variant.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeviceClassKind {
+    Miscellaneous(Miscellaneous),
+    Computer(Computer),
+    Phone(Phone),
+    LanNetworkAccessPoint(LanNetworkAccessPoint),
+    AudioVideo(AudioVideo),
+    Peripheral(Peripheral),
+    Imaging(Imaging),
+    Wearable(Wearable),
+    Toy(Toy),
+    Health(Health),
+    Uncategorized(Uncategorized),
+}
+
+/// The result of decoding a raw class of device `u32` into its typed service and device
+/// classes, mirroring the accessors WinRT exposes on `BluetoothClassOfDevice`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParsedClassOfDevice {
+    pub major_service_class: MajorServiceClass,
+    pub device_class: DeviceClassKind,
+}
+
+/// Recover a MajorServiceClass by testing each bit that `MajorServiceClass::major_service_class`
+/// sets.
+fn parse_major_service_class(cod: u32) -> MajorServiceClass {
+    MajorServiceClass {
+        limited_discoverable_mode: cod & (1 << 13) != 0,
+        le_audio: cod & (1 << 14) != 0,
+        positioning: cod & (1 << 16) != 0,
+        networking: cod & (1 << 17) != 0,
+        rendering: cod & (1 << 18) != 0,
+        capturing: cod & (1 << 19) != 0,
+        object_transfer: cod & (1 << 20) != 0,
+        audio: cod & (1 << 21) != 0,
+        telephony: cod & (1 << 22) != 0,
+        information: cod & (1 << 23) != 0,
+    }
+}
+
+/// Decode a raw class of device `u32`, as produced by [`make_class_of_device`], back into a
+/// typed [`ParsedClassOfDevice`].
+pub fn parse_class_of_device(cod: u32) -> Result<ParsedClassOfDevice, ParseClassOfDeviceError> {
+    if cod & 0b11 != 0b00 {
+        return Err(ParseClassOfDeviceError::InvalidFormatType);
+    }
+
+    let major_service_class = parse_major_service_class(cod);
+
+    let minor = cod & 0xFC;
+    let device_class = match cod & 0x1F00 {
+        0x0000 => DeviceClassKind::Miscellaneous(Miscellaneous { minor_device_class: minor }),
+        0x0100 => DeviceClassKind::Computer(match minor {
+            0x04 => Computer::DesktopWorkstation,
+            0x08 => Computer::ServerClassComputer,
+            0x0C => Computer::Laptop,
+            0x10 => Computer::HandheldPcPda,
+            0x14 => Computer::PalmSizedPcPda,
+            0x18 => Computer::WearableComputer,
+            0x1C => Computer::Tablet,
+            _ => Computer::Uncategorized,
+        }),
+        0x0200 => DeviceClassKind::Phone(match minor {
+            0x04 => Phone::Cellular,
+            0x08 => Phone::Cordless,
+            0x0C => Phone::Smartphone,
+            0x10 => Phone::WiredModemOrVoiceGateway,
+            0x14 => Phone::CommonIsdnAccess,
+            _ => Phone::Uncategorized,
+        }),
+        0x0300 => DeviceClassKind::LanNetworkAccessPoint(match minor & 0xE0 {
+            0x20 => LanNetworkAccessPoint::Utilized1To17Percent,
+            0x40 => LanNetworkAccessPoint::Utilized17To33Percent,
+            0x60 => LanNetworkAccessPoint::Utilized33To50Percent,
+            0x80 => LanNetworkAccessPoint::Utilized50To67Percent,
+            0xA0 => LanNetworkAccessPoint::Utilized67To83Percent,
+            0xC0 => LanNetworkAccessPoint::Utilized83To99Percent,
+            0xE0 => LanNetworkAccessPoint::NoServiceAvailable,
+            _ => LanNetworkAccessPoint::FullyAvailable,
+        }),
+        0x0400 => DeviceClassKind::AudioVideo(match minor {
+            0x04 => AudioVideo::WearableHeadsetDevice,
+            0x08 => AudioVideo::HandsFreeDevice,
+            0x10 => AudioVideo::Microphone,
+            0x14 => AudioVideo::Loudspeaker,
+            0x18 => AudioVideo::Headphones,
+            0x1C => AudioVideo::PortableAudio,
+            0x20 => AudioVideo::CarAudio,
+            0x24 => AudioVideo::SetTopBox,
+            0x28 => AudioVideo::HiFiAudioDevice,
+            0x2C => AudioVideo::Vcr,
+            0x30 => AudioVideo::VideoCamera,
+            0x34 => AudioVideo::Camcorder,
+            0x38 => AudioVideo::VideoMonitor,
+            0x3C => AudioVideo::VideoDisplayAndLoudspeaker,
+            0x40 => AudioVideo::VideoConferencing,
+            0x48 => AudioVideo::GamingToy,
+            _ => AudioVideo::Uncategorized,
+        }),
+        0x0500 => DeviceClassKind::Peripheral(Peripheral::new(
+            match minor & 0xC0 {
+                0x40 => PeripheralUpper::Keyboard,
+                0x80 => PeripheralUpper::PointingDevice,
+                0xC0 => PeripheralUpper::ComboKeyboardPointingDevice,
+                _ => PeripheralUpper::Uncategorized,
+            },
+            match minor & 0x3C {
+                0x04 => PeripheralLower::Joystick,
+                0x08 => PeripheralLower::Gamepad,
+                0x0C => PeripheralLower::RemoteControl,
+                0x10 => PeripheralLower::SensingDevice,
+                0x14 => PeripheralLower::DigitizerTablet,
+                0x18 => PeripheralLower::CardReader,
+                0x1C => PeripheralLower::DigitalPen,
+                0x20 => PeripheralLower::HandheldScanner,
+                0x24 => PeripheralLower::HandheldGesturalInputDevice,
+                _ => PeripheralLower::Uncategorized,
+            },
+        )),
+        0x0600 => DeviceClassKind::Imaging(Imaging {
+            display: minor & (1 << 4) != 0,
+            camera: minor & (1 << 5) != 0,
+            scanner: minor & (1 << 6) != 0,
+            printer: minor & (1 << 7) != 0,
+        }),
+        0x0700 => DeviceClassKind::Wearable(match minor {
+            0x08 => Wearable::Pager,
+            0x0C => Wearable::Jacket,
+            0x10 => Wearable::Helmet,
+            0x14 => Wearable::Glasses,
+            0x18 => Wearable::Pin,
+            _ => Wearable::Wristwatch,
+        }),
+        0x0800 => DeviceClassKind::Toy(match minor {
+            0x08 => Toy::Vehicle,
+            0x0C => Toy::DollActionFigure,
+            0x10 => Toy::Controller,
+            0x14 => Toy::Game,
+            _ => Toy::Robot,
+        }),
+        0x0900 => DeviceClassKind::Health(match minor {
+            0x04 => Health::BloodPressureMonitor,
+            0x08 => Health::Thermometer,
+            0x0C => Health::WeighingScale,
+            0x10 => Health::GlucoseMeter,
+            0x14 => Health::PulseOximeter,
+            0x18 => Health::HeartPulseRateMonitor,
+            0x1C => Health::HealthDataDisplay,
+            0x20 => Health::StepCounter,
+            0x24 => Health::BodyCompositionAnalyzer,
+            0x28 => Health::PeakFlowMonitor,
+            0x2C => Health::MedicationMonitor,
+            0x30 => Health::KneeProsthesis,
+            0x34 => Health::AnkleProsthesis,
+            0x38 => Health::GenericHealthManager,
+            0x3C => Health::PersonalMobilityDevice,
+            _ => Health::Undefined,
+        }),
+        _ => DeviceClassKind::Uncategorized(Uncategorized { minor_device_class: minor }),
+    };
+
+    Ok(ParsedClassOfDevice { major_service_class, device_class })
+}
+
+impl std::fmt::Display for DeviceClassKind {
+    /// Format this DeviceClassKind as `"<category>/<minor>"`, e.g. `"computer/laptop"` or
+    /// `"audio-video/headset"`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Miscellaneous(m) => write!(f, "miscellaneous/{:#x}", m.minor_device_class),
+            Self::Computer(c) => write!(f, "computer/{c}"),
+            Self::Phone(p) => write!(f, "phone/{p}"),
+            Self::LanNetworkAccessPoint(l) => write!(f, "lan-network-access-point/{l}"),
+            Self::AudioVideo(a) => write!(f, "audio-video/{a}"),
+            Self::Peripheral(p) => write!(f, "peripheral/{p}"),
+            Self::Imaging(i) => write!(f, "imaging/{i}"),
+            Self::Wearable(w) => write!(f, "wearable/{w}"),
+            Self::Toy(t) => write!(f, "toy/{t}"),
+            Self::Health(h) => write!(f, "health/{h}"),
+            Self::Uncategorized(u) => write!(f, "uncategorized/{:#x}", u.minor_device_class),
+        }
+    }
+}
+
+impl std::str::FromStr for DeviceClassKind {
+    type Err = ParseClassNameError;
+
+    /// Parse a DeviceClassKind from a `"<category>/<minor>"` string, as produced by `Display`,
+    /// so a CLI or config file can specify a device class by name.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (category, minor) = s
+            .split_once('/')
+            .ok_or_else(|| ParseClassNameError(s.to_string()))?;
+        let parse_hex = |minor: &str| {
+            u32::from_str_radix(minor.trim_start_matches("0x"), 16)
+                .map_err(|_| ParseClassNameError(s.to_string()))
+        };
+        match category {
+            "miscellaneous" => Ok(Self::Miscellaneous(Miscellaneous { minor_device_class: parse_hex(minor)? })),
+            "computer" => Ok(Self::Computer(minor.parse().map_err(|_| ParseClassNameError(s.to_string()))?)),
+            "phone" => Ok(Self::Phone(minor.parse().map_err(|_| ParseClassNameError(s.to_string()))?)),
+            "lan-network-access-point" => Ok(Self::LanNetworkAccessPoint(minor.parse().map_err(|_| ParseClassNameError(s.to_string()))?)),
+            "audio-video" => Ok(Self::AudioVideo(minor.parse().map_err(|_| ParseClassNameError(s.to_string()))?)),
+            "peripheral" => Ok(Self::Peripheral(minor.parse().map_err(|_| ParseClassNameError(s.to_string()))?)),
+            "imaging" => Ok(Self::Imaging(minor.parse().map_err(|_| ParseClassNameError(s.to_string()))?)),
+            "wearable" => Ok(Self::Wearable(minor.parse().map_err(|_| ParseClassNameError(s.to_string()))?)),
+            "toy" => Ok(Self::Toy(minor.parse().map_err(|_| ParseClassNameError(s.to_string()))?)),
+            "health" => Ok(Self::Health(minor.parse().map_err(|_| ParseClassNameError(s.to_string()))?)),
+            "uncategorized" => Ok(Self::Uncategorized(Uncategorized { minor_device_class: parse_hex(minor)? })),
+            _ => Err(ParseClassNameError(s.to_string())),
+        }
+    }
+}
+
+// ClassOfDevice
+
+/// A fully-assembled Bluetooth class of device value.
+///
+/// This mirrors the property surface of WinRT's `BluetoothClassOfDevice`: a single type to
+/// store, compare, and hand to a stack, instead of an opaque `u32`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ClassOfDevice(u32);
+
+impl ClassOfDevice {
+    /// Start building a ClassOfDevice from a service class and device class.
+    pub fn builder() -> ClassOfDeviceBuilder {
+        ClassOfDeviceBuilder::default()
+    }
+
+    /// Returns the major device class bits of this class of device.
+    pub fn major_device_class(&self) -> u32 {
+        self.0 & 0x1F00
+    }
+
+    /// Returns the minor device class bits of this class of device.
+    pub fn minor_device_class(&self) -> u32 {
+        self.0 & 0xFC
+    }
+
+    /// Returns the service capabilities advertised by this class of device.
+    pub fn service_capabilities(&self) -> MajorServiceClass {
+        parse_major_service_class(self.0)
+    }
+}
+
+impl From<ClassOfDevice> for u32 {
+    fn from(class_of_device: ClassOfDevice) -> u32 {
+        class_of_device.0
+    }
+}
+
+impl TryFrom<u32> for ClassOfDevice {
+    type Error = ParseClassOfDeviceError;
+
+    fn try_from(cod: u32) -> Result<Self, Self::Error> {
+        if cod & 0b11 != 0b00 {
+            return Err(ParseClassOfDeviceError::InvalidFormatType);
+        }
+        Ok(Self(cod))
+    }
+}
+
+/// Builder for a [`ClassOfDevice`], combining a [`MajorServiceClass`] with a [`DeviceClass`].
+///
+/// ```
+/// use hid_device_class::{ClassOfDevice, MajorServiceClass, Computer};
+///
+/// let cod = ClassOfDevice::builder()
+///     .service(MajorServiceClass { audio: true, ..MajorServiceClass::empty() })
+///     .device(Computer::Laptop)
+///     .build();
+/// # let _ = cod;
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ClassOfDeviceBuilder {
+    major_service_class: MajorServiceClass,
+    device_class: u32,
+}
+
+impl ClassOfDeviceBuilder {
+    /// Set the service class of the ClassOfDevice under construction.
+    pub fn service(mut self, major_service_class: MajorServiceClass) -> Self {
+        self.major_service_class = major_service_class;
+        self
+    }
+
+    /// Set the device class of the ClassOfDevice under construction.
+    pub fn device<C: DeviceClass>(mut self, device_class: C) -> Self {
+        self.device_class = device_class.device_class();
+        self
+    }
+
+    /// Assemble the configured service and device classes into a ClassOfDevice.
+    pub fn build(self) -> ClassOfDevice {
+        ClassOfDevice(self.major_service_class.major_service_class() | self.device_class)
+    }
+}
+
 
 // Miscellaneous class
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct Miscellaneous {
     pub minor_device_class: u32,
 }
@@ -105,6 +506,8 @@ impl MinorDeviceClass for Miscellaneous {
 
 // Computer class
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum Computer {
     #[default]
@@ -141,8 +544,46 @@ impl MinorDeviceClass for Computer {
     }
 }
 
+impl std::fmt::Display for Computer {
+    /// Format this Computer as its canonical lowercase name.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Uncategorized => "uncategorized",
+            Self::DesktopWorkstation => "desktop workstation",
+            Self::ServerClassComputer => "server",
+            Self::Laptop => "laptop",
+            Self::HandheldPcPda => "handheld pc/pda",
+            Self::PalmSizedPcPda => "palm-sized pc/pda",
+            Self::WearableComputer => "wearable computer",
+            Self::Tablet => "tablet",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl std::str::FromStr for Computer {
+    type Err = ParseClassNameError;
+
+    /// Parse a Computer from its canonical lowercase name, as produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "uncategorized" => Ok(Self::Uncategorized),
+            "desktop workstation" => Ok(Self::DesktopWorkstation),
+            "server" => Ok(Self::ServerClassComputer),
+            "laptop" => Ok(Self::Laptop),
+            "handheld pc/pda" => Ok(Self::HandheldPcPda),
+            "palm-sized pc/pda" => Ok(Self::PalmSizedPcPda),
+            "wearable computer" => Ok(Self::WearableComputer),
+            "tablet" => Ok(Self::Tablet),
+            _ => Err(ParseClassNameError(s.to_string())),
+        }
+    }
+}
+
 // Phone
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum Phone {
     #[default]
@@ -175,6 +616,40 @@ impl MinorDeviceClass for Phone {
     }
 }
 
+impl std::fmt::Display for Phone {
+    /// Format this Phone as its canonical lowercase name.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Uncategorized => "uncategorized",
+            Self::Cellular => "cellular",
+            Self::Cordless => "cordless",
+            Self::Smartphone => "smartphone",
+            Self::WiredModemOrVoiceGateway => "wired modem or voice gateway",
+            Self::CommonIsdnAccess => "common isdn access",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl std::str::FromStr for Phone {
+    type Err = ParseClassNameError;
+
+    /// Parse a Phone from its canonical lowercase name, as produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "uncategorized" => Ok(Self::Uncategorized),
+            "cellular" => Ok(Self::Cellular),
+            "cordless" => Ok(Self::Cordless),
+            "smartphone" => Ok(Self::Smartphone),
+            "wired modem or voice gateway" => Ok(Self::WiredModemOrVoiceGateway),
+            "common isdn access" => Ok(Self::CommonIsdnAccess),
+            _ => Err(ParseClassNameError(s.to_string())),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum LanNetworkAccessPoint {
     #[default]
@@ -211,6 +686,44 @@ impl MinorDeviceClass for LanNetworkAccessPoint {
     }
 }
 
+impl std::fmt::Display for LanNetworkAccessPoint {
+    /// Format this LanNetworkAccessPoint as its canonical lowercase name.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::FullyAvailable => "fully available",
+            Self::Utilized1To17Percent => "1-17 percent",
+            Self::Utilized17To33Percent => "17-33 percent",
+            Self::Utilized33To50Percent => "33-50 percent",
+            Self::Utilized50To67Percent => "50-67 percent",
+            Self::Utilized67To83Percent => "67-83 percent",
+            Self::Utilized83To99Percent => "83-99 percent",
+            Self::NoServiceAvailable => "no service available",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl std::str::FromStr for LanNetworkAccessPoint {
+    type Err = ParseClassNameError;
+
+    /// Parse a LanNetworkAccessPoint from its canonical lowercase name, as produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fully available" => Ok(Self::FullyAvailable),
+            "1-17 percent" => Ok(Self::Utilized1To17Percent),
+            "17-33 percent" => Ok(Self::Utilized17To33Percent),
+            "33-50 percent" => Ok(Self::Utilized33To50Percent),
+            "50-67 percent" => Ok(Self::Utilized50To67Percent),
+            "67-83 percent" => Ok(Self::Utilized67To83Percent),
+            "83-99 percent" => Ok(Self::Utilized83To99Percent),
+            "no service available" => Ok(Self::NoServiceAvailable),
+            _ => Err(ParseClassNameError(s.to_string())),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum AudioVideo {
     #[default]
@@ -238,7 +751,7 @@ pub enum AudioVideo {
 impl MajorDeviceClass for AudioVideo {
     /// Returns the major class for all AudioVideo classes.
     fn major_device_class() -> u32 {
-        0x0500
+        0x0400
     }
 }
 
@@ -269,8 +782,64 @@ impl MinorDeviceClass for AudioVideo {
     }
 }
 
+impl std::fmt::Display for AudioVideo {
+    /// Format this AudioVideo as its canonical lowercase name.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Uncategorized => "uncategorized",
+            Self::WearableHeadsetDevice => "headset",
+            Self::HandsFreeDevice => "handsfree",
+            Self::Microphone => "microphone",
+            Self::Loudspeaker => "loudspeaker",
+            Self::Headphones => "headphones",
+            Self::PortableAudio => "portable audio",
+            Self::CarAudio => "car audio",
+            Self::SetTopBox => "set-top box",
+            Self::HiFiAudioDevice => "hifi audio device",
+            Self::Vcr => "vcr",
+            Self::VideoCamera => "video camera",
+            Self::Camcorder => "camcorder",
+            Self::VideoMonitor => "video monitor",
+            Self::VideoDisplayAndLoudspeaker => "video display and loudspeaker",
+            Self::VideoConferencing => "video conferencing",
+            Self::GamingToy => "gaming toy",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl std::str::FromStr for AudioVideo {
+    type Err = ParseClassNameError;
+
+    /// Parse an AudioVideo from its canonical lowercase name, as produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "uncategorized" => Ok(Self::Uncategorized),
+            "headset" => Ok(Self::WearableHeadsetDevice),
+            "handsfree" => Ok(Self::HandsFreeDevice),
+            "microphone" => Ok(Self::Microphone),
+            "loudspeaker" => Ok(Self::Loudspeaker),
+            "headphones" => Ok(Self::Headphones),
+            "portable audio" => Ok(Self::PortableAudio),
+            "car audio" => Ok(Self::CarAudio),
+            "set-top box" => Ok(Self::SetTopBox),
+            "hifi audio device" => Ok(Self::HiFiAudioDevice),
+            "vcr" => Ok(Self::Vcr),
+            "video camera" => Ok(Self::VideoCamera),
+            "camcorder" => Ok(Self::Camcorder),
+            "video monitor" => Ok(Self::VideoMonitor),
+            "video display and loudspeaker" => Ok(Self::VideoDisplayAndLoudspeaker),
+            "video conferencing" => Ok(Self::VideoConferencing),
+            "gaming toy" => Ok(Self::GamingToy),
+            _ => Err(ParseClassNameError(s.to_string())),
+        }
+    }
+}
+
 // Peripheral class
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum PeripheralUpper {
     #[default]
@@ -291,6 +860,36 @@ impl PeripheralUpper {
     }
 }
 
+impl std::fmt::Display for PeripheralUpper {
+    /// Format this PeripheralUpper as its canonical lowercase name.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Uncategorized => "uncategorized",
+            Self::Keyboard => "keyboard",
+            Self::PointingDevice => "pointing",
+            Self::ComboKeyboardPointingDevice => "combo",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl std::str::FromStr for PeripheralUpper {
+    type Err = ParseClassNameError;
+
+    /// Parse a PeripheralUpper from its canonical lowercase name, as produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "uncategorized" => Ok(Self::Uncategorized),
+            "keyboard" => Ok(Self::Keyboard),
+            "pointing" => Ok(Self::PointingDevice),
+            "combo" => Ok(Self::ComboKeyboardPointingDevice),
+            _ => Err(ParseClassNameError(s.to_string())),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum PeripheralLower {
     #[default]
@@ -323,6 +922,47 @@ impl PeripheralLower {
     }
 }
 
+impl std::fmt::Display for PeripheralLower {
+    /// Format this PeripheralLower as its canonical lowercase name.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Uncategorized => "uncategorized",
+            Self::Joystick => "joystick",
+            Self::Gamepad => "gamepad",
+            Self::RemoteControl => "remote control",
+            Self::SensingDevice => "sensing device",
+            Self::DigitizerTablet => "digitizer tablet",
+            Self::CardReader => "card reader",
+            Self::DigitalPen => "digital pen",
+            Self::HandheldScanner => "handheld scanner",
+            Self::HandheldGesturalInputDevice => "handheld gestural input device",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl std::str::FromStr for PeripheralLower {
+    type Err = ParseClassNameError;
+
+    /// Parse a PeripheralLower from its canonical lowercase name, as produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "uncategorized" => Ok(Self::Uncategorized),
+            "joystick" => Ok(Self::Joystick),
+            "gamepad" => Ok(Self::Gamepad),
+            "remote control" => Ok(Self::RemoteControl),
+            "sensing device" => Ok(Self::SensingDevice),
+            "digitizer tablet" => Ok(Self::DigitizerTablet),
+            "card reader" => Ok(Self::CardReader),
+            "digital pen" => Ok(Self::DigitalPen),
+            "handheld scanner" => Ok(Self::HandheldScanner),
+            "handheld gestural input device" => Ok(Self::HandheldGesturalInputDevice),
+            _ => Err(ParseClassNameError(s.to_string())),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct Peripheral {
     pub upper: PeripheralUpper,
@@ -350,6 +990,46 @@ impl MinorDeviceClass for Peripheral {
     }
 }
 
+impl std::fmt::Display for Peripheral {
+    /// Format this Peripheral as its upper and lower part joined by `+`, e.g. `"keyboard+joystick"`.
+    /// A part that is `Uncategorized` is only shown if the other part is too.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.upper, self.lower) {
+            (PeripheralUpper::Uncategorized, PeripheralLower::Uncategorized) => {
+                write!(f, "uncategorized")
+            }
+            (upper, PeripheralLower::Uncategorized) => write!(f, "{upper}"),
+            (PeripheralUpper::Uncategorized, lower) => write!(f, "{lower}"),
+            (upper, lower) => write!(f, "{upper}+{lower}"),
+        }
+    }
+}
+
+impl std::str::FromStr for Peripheral {
+    type Err = ParseClassNameError;
+
+    /// Parse a Peripheral from its upper and lower part names joined by `+`, in either order.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "uncategorized" {
+            return Ok(Self::new(PeripheralUpper::Uncategorized, PeripheralLower::Uncategorized));
+        }
+
+        let mut upper = PeripheralUpper::Uncategorized;
+        let mut lower = PeripheralLower::Uncategorized;
+        for part in s.split('+') {
+            if let Ok(parsed_upper) = part.parse::<PeripheralUpper>() {
+                upper = parsed_upper;
+            } else if let Ok(parsed_lower) = part.parse::<PeripheralLower>() {
+                lower = parsed_lower;
+            } else {
+                return Err(ParseClassNameError(s.to_string()));
+            }
+        }
+        Ok(Self::new(upper, lower))
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct Imaging {
     pub display: bool,
@@ -366,6 +1046,7 @@ impl MajorDeviceClass for Imaging {
 
 impl MinorDeviceClass for Imaging {
     /// Convert this Imaging class into a minor device class.
+    #[allow(clippy::identity_op)]
     fn minor_device_class(&self) -> u32 {
         0
             | if self.display { 1 << 4 } else { 0 }
@@ -375,6 +1056,48 @@ impl MinorDeviceClass for Imaging {
     }
 }
 
+impl std::fmt::Display for Imaging {
+    /// Format this Imaging class as its set capabilities joined by `+`, e.g. `"camera+scanner"`,
+    /// or `"uncategorized"` if none are set.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut capabilities = Vec::new();
+        if self.display { capabilities.push("display"); }
+        if self.camera { capabilities.push("camera"); }
+        if self.scanner { capabilities.push("scanner"); }
+        if self.printer { capabilities.push("printer"); }
+
+        if capabilities.is_empty() {
+            write!(f, "uncategorized")
+        } else {
+            write!(f, "{}", capabilities.join("+"))
+        }
+    }
+}
+
+impl std::str::FromStr for Imaging {
+    type Err = ParseClassNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "uncategorized" {
+            return Ok(Self::default());
+        }
+
+        let mut imaging = Self::default();
+        for part in s.split('+') {
+            match part {
+                "display" => imaging.display = true,
+                "camera" => imaging.camera = true,
+                "scanner" => imaging.scanner = true,
+                "printer" => imaging.printer = true,
+                _ => return Err(ParseClassNameError(s.to_string())),
+            }
+        }
+        Ok(imaging)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Wearable {
     Wristwatch,
@@ -406,6 +1129,40 @@ impl MinorDeviceClass for Wearable {
     }
 }
 
+impl std::fmt::Display for Wearable {
+    /// Format this Wearable as its canonical lowercase name.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Wristwatch => "wristwatch",
+            Self::Pager => "pager",
+            Self::Jacket => "jacket",
+            Self::Helmet => "helmet",
+            Self::Glasses => "glasses",
+            Self::Pin => "pin",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl std::str::FromStr for Wearable {
+    type Err = ParseClassNameError;
+
+    /// Parse a Wearable from its canonical lowercase name, as produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "wristwatch" => Ok(Self::Wristwatch),
+            "pager" => Ok(Self::Pager),
+            "jacket" => Ok(Self::Jacket),
+            "helmet" => Ok(Self::Helmet),
+            "glasses" => Ok(Self::Glasses),
+            "pin" => Ok(Self::Pin),
+            _ => Err(ParseClassNameError(s.to_string())),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Toy {
     Robot,
@@ -435,8 +1192,40 @@ impl MinorDeviceClass for Toy {
     }
 }
 
+impl std::fmt::Display for Toy {
+    /// Format this Toy as its canonical lowercase name.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Robot => "robot",
+            Self::Vehicle => "vehicle",
+            Self::DollActionFigure => "doll / action figure",
+            Self::Controller => "controller",
+            Self::Game => "game",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl std::str::FromStr for Toy {
+    type Err = ParseClassNameError;
+
+    /// Parse a Toy from its canonical lowercase name, as produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "robot" => Ok(Self::Robot),
+            "vehicle" => Ok(Self::Vehicle),
+            "doll / action figure" => Ok(Self::DollActionFigure),
+            "controller" => Ok(Self::Controller),
+            "game" => Ok(Self::Game),
+            _ => Err(ParseClassNameError(s.to_string())),
+        }
+    }
+}
+
 // Health
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum Health {
     #[default]
@@ -489,8 +1278,61 @@ impl MinorDeviceClass for Health {
     }
 }
 
+impl std::fmt::Display for Health {
+    /// Format this Health as its canonical lowercase name.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Undefined => "undefined",
+            Self::BloodPressureMonitor => "blood pressure monitor",
+            Self::Thermometer => "thermometer",
+            Self::WeighingScale => "weighing scale",
+            Self::GlucoseMeter => "glucose meter",
+            Self::PulseOximeter => "pulse oximeter",
+            Self::HeartPulseRateMonitor => "heart/pulse rate monitor",
+            Self::HealthDataDisplay => "health data display",
+            Self::StepCounter => "step counter",
+            Self::BodyCompositionAnalyzer => "body composition analyzer",
+            Self::PeakFlowMonitor => "peak flow monitor",
+            Self::MedicationMonitor => "medication monitor",
+            Self::KneeProsthesis => "knee prosthesis",
+            Self::AnkleProsthesis => "ankle prosthesis",
+            Self::GenericHealthManager => "generic health manager",
+            Self::PersonalMobilityDevice => "personal mobility device",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl std::str::FromStr for Health {
+    type Err = ParseClassNameError;
+
+    /// Parse a Health from its canonical lowercase name, as produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "undefined" => Ok(Self::Undefined),
+            "blood pressure monitor" => Ok(Self::BloodPressureMonitor),
+            "thermometer" => Ok(Self::Thermometer),
+            "weighing scale" => Ok(Self::WeighingScale),
+            "glucose meter" => Ok(Self::GlucoseMeter),
+            "pulse oximeter" => Ok(Self::PulseOximeter),
+            "heart/pulse rate monitor" => Ok(Self::HeartPulseRateMonitor),
+            "health data display" => Ok(Self::HealthDataDisplay),
+            "step counter" => Ok(Self::StepCounter),
+            "body composition analyzer" => Ok(Self::BodyCompositionAnalyzer),
+            "peak flow monitor" => Ok(Self::PeakFlowMonitor),
+            "medication monitor" => Ok(Self::MedicationMonitor),
+            "knee prosthesis" => Ok(Self::KneeProsthesis),
+            "ankle prosthesis" => Ok(Self::AnkleProsthesis),
+            "generic health manager" => Ok(Self::GenericHealthManager),
+            "personal mobility device" => Ok(Self::PersonalMobilityDevice),
+            _ => Err(ParseClassNameError(s.to_string())),
+        }
+    }
+}
+
 // Uncategorizrd
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct Uncategorized {
     pub minor_device_class: u32,
@@ -509,3 +1351,368 @@ impl MinorDeviceClass for Uncategorized {
         self.minor_device_class
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn audio_video_major_device_class_is_0x0400() {
+        // Regression test: this major class used to collide with Peripheral's 0x0500, which
+        // made parse_class_of_device ambiguous.
+        assert_eq!(AudioVideo::major_device_class(), 0x0400);
+    }
+
+    #[test]
+    fn parse_class_of_device_rejects_invalid_format_type() {
+        assert_eq!(
+            parse_class_of_device(0b01),
+            Err(ParseClassOfDeviceError::InvalidFormatType),
+        );
+    }
+
+    #[test]
+    fn parse_class_of_device_round_trips_every_computer_variant() {
+        for computer in [
+            Computer::Uncategorized,
+            Computer::DesktopWorkstation,
+            Computer::ServerClassComputer,
+            Computer::Laptop,
+            Computer::HandheldPcPda,
+            Computer::PalmSizedPcPda,
+            Computer::WearableComputer,
+            Computer::Tablet,
+        ] {
+            let cod = make_class_of_device(MajorServiceClass::empty(), computer);
+            let parsed = parse_class_of_device(cod).unwrap();
+            assert_eq!(parsed.device_class, DeviceClassKind::Computer(computer));
+        }
+    }
+
+    #[test]
+    fn parse_class_of_device_round_trips_every_phone_variant() {
+        for phone in [
+            Phone::Uncategorized,
+            Phone::Cellular,
+            Phone::Cordless,
+            Phone::Smartphone,
+            Phone::WiredModemOrVoiceGateway,
+            Phone::CommonIsdnAccess,
+        ] {
+            let cod = make_class_of_device(MajorServiceClass::empty(), phone);
+            let parsed = parse_class_of_device(cod).unwrap();
+            assert_eq!(parsed.device_class, DeviceClassKind::Phone(phone));
+        }
+    }
+
+    #[test]
+    fn parse_class_of_device_round_trips_every_lan_network_access_point_variant() {
+        for lan in [
+            LanNetworkAccessPoint::FullyAvailable,
+            LanNetworkAccessPoint::Utilized1To17Percent,
+            LanNetworkAccessPoint::Utilized17To33Percent,
+            LanNetworkAccessPoint::Utilized33To50Percent,
+            LanNetworkAccessPoint::Utilized50To67Percent,
+            LanNetworkAccessPoint::Utilized67To83Percent,
+            LanNetworkAccessPoint::Utilized83To99Percent,
+            LanNetworkAccessPoint::NoServiceAvailable,
+        ] {
+            let cod = make_class_of_device(MajorServiceClass::empty(), lan);
+            let parsed = parse_class_of_device(cod).unwrap();
+            assert_eq!(parsed.device_class, DeviceClassKind::LanNetworkAccessPoint(lan));
+        }
+    }
+
+    #[test]
+    fn parse_class_of_device_round_trips_every_audio_video_variant() {
+        for audio_video in [
+            AudioVideo::Uncategorized,
+            AudioVideo::WearableHeadsetDevice,
+            AudioVideo::HandsFreeDevice,
+            AudioVideo::Microphone,
+            AudioVideo::Loudspeaker,
+            AudioVideo::Headphones,
+            AudioVideo::PortableAudio,
+            AudioVideo::CarAudio,
+            AudioVideo::SetTopBox,
+            AudioVideo::HiFiAudioDevice,
+            AudioVideo::Vcr,
+            AudioVideo::VideoCamera,
+            AudioVideo::Camcorder,
+            AudioVideo::VideoMonitor,
+            AudioVideo::VideoDisplayAndLoudspeaker,
+            AudioVideo::VideoConferencing,
+            AudioVideo::GamingToy,
+        ] {
+            let cod = make_class_of_device(MajorServiceClass::empty(), audio_video);
+            let parsed = parse_class_of_device(cod).unwrap();
+            assert_eq!(parsed.device_class, DeviceClassKind::AudioVideo(audio_video));
+        }
+    }
+
+    #[test]
+    fn parse_class_of_device_round_trips_every_peripheral_combination() {
+        let uppers = [
+            PeripheralUpper::Uncategorized,
+            PeripheralUpper::Keyboard,
+            PeripheralUpper::PointingDevice,
+            PeripheralUpper::ComboKeyboardPointingDevice,
+        ];
+        let lowers = [
+            PeripheralLower::Uncategorized,
+            PeripheralLower::Joystick,
+            PeripheralLower::Gamepad,
+            PeripheralLower::RemoteControl,
+            PeripheralLower::SensingDevice,
+            PeripheralLower::DigitizerTablet,
+            PeripheralLower::CardReader,
+            PeripheralLower::DigitalPen,
+            PeripheralLower::HandheldScanner,
+            PeripheralLower::HandheldGesturalInputDevice,
+        ];
+        for upper in uppers {
+            for lower in lowers {
+                let peripheral = Peripheral::new(upper, lower);
+                let cod = make_class_of_device(MajorServiceClass::empty(), peripheral);
+                let parsed = parse_class_of_device(cod).unwrap();
+                assert_eq!(parsed.device_class, DeviceClassKind::Peripheral(peripheral));
+            }
+        }
+    }
+
+    #[test]
+    fn parse_class_of_device_round_trips_every_imaging_combination() {
+        for display in [false, true] {
+            for camera in [false, true] {
+                for scanner in [false, true] {
+                    for printer in [false, true] {
+                        let imaging = Imaging { display, camera, scanner, printer };
+                        let cod = make_class_of_device(MajorServiceClass::empty(), imaging);
+                        let parsed = parse_class_of_device(cod).unwrap();
+                        assert_eq!(parsed.device_class, DeviceClassKind::Imaging(imaging));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parse_class_of_device_round_trips_every_wearable_variant() {
+        for wearable in [
+            Wearable::Wristwatch,
+            Wearable::Pager,
+            Wearable::Jacket,
+            Wearable::Helmet,
+            Wearable::Glasses,
+            Wearable::Pin,
+        ] {
+            let cod = make_class_of_device(MajorServiceClass::empty(), wearable);
+            let parsed = parse_class_of_device(cod).unwrap();
+            assert_eq!(parsed.device_class, DeviceClassKind::Wearable(wearable));
+        }
+    }
+
+    #[test]
+    fn parse_class_of_device_round_trips_every_toy_variant() {
+        for toy in [
+            Toy::Robot,
+            Toy::Vehicle,
+            Toy::DollActionFigure,
+            Toy::Controller,
+            Toy::Game,
+        ] {
+            let cod = make_class_of_device(MajorServiceClass::empty(), toy);
+            let parsed = parse_class_of_device(cod).unwrap();
+            assert_eq!(parsed.device_class, DeviceClassKind::Toy(toy));
+        }
+    }
+
+    #[test]
+    fn parse_class_of_device_round_trips_every_health_variant() {
+        for health in [
+            Health::Undefined,
+            Health::BloodPressureMonitor,
+            Health::Thermometer,
+            Health::WeighingScale,
+            Health::GlucoseMeter,
+            Health::PulseOximeter,
+            Health::HeartPulseRateMonitor,
+            Health::HealthDataDisplay,
+            Health::StepCounter,
+            Health::BodyCompositionAnalyzer,
+            Health::PeakFlowMonitor,
+            Health::MedicationMonitor,
+            Health::KneeProsthesis,
+            Health::AnkleProsthesis,
+            Health::GenericHealthManager,
+            Health::PersonalMobilityDevice,
+        ] {
+            let cod = make_class_of_device(MajorServiceClass::empty(), health);
+            let parsed = parse_class_of_device(cod).unwrap();
+            assert_eq!(parsed.device_class, DeviceClassKind::Health(health));
+        }
+    }
+
+    #[test]
+    fn parse_class_of_device_round_trips_major_service_class() {
+        let service_class = MajorServiceClass {
+            limited_discoverable_mode: true,
+            le_audio: false,
+            positioning: true,
+            networking: false,
+            rendering: true,
+            capturing: false,
+            object_transfer: true,
+            audio: false,
+            telephony: true,
+            information: false,
+        };
+        let cod = make_class_of_device(service_class, Computer::Laptop);
+        let parsed = parse_class_of_device(cod).unwrap();
+        assert_eq!(parsed.major_service_class, service_class);
+    }
+
+    #[test]
+    fn computer_display_from_str_round_trips() {
+        for computer in [
+            Computer::Uncategorized,
+            Computer::DesktopWorkstation,
+            Computer::ServerClassComputer,
+            Computer::Laptop,
+            Computer::HandheldPcPda,
+            Computer::PalmSizedPcPda,
+            Computer::WearableComputer,
+            Computer::Tablet,
+        ] {
+            assert_eq!(computer.to_string().parse(), Ok(computer));
+        }
+    }
+
+    #[test]
+    fn phone_display_from_str_round_trips() {
+        for phone in [
+            Phone::Uncategorized,
+            Phone::Cellular,
+            Phone::Cordless,
+            Phone::Smartphone,
+            Phone::WiredModemOrVoiceGateway,
+            Phone::CommonIsdnAccess,
+        ] {
+            assert_eq!(phone.to_string().parse(), Ok(phone));
+        }
+    }
+
+    #[test]
+    fn audio_video_display_from_str_round_trips() {
+        for audio_video in [
+            AudioVideo::Uncategorized,
+            AudioVideo::WearableHeadsetDevice,
+            AudioVideo::HandsFreeDevice,
+            AudioVideo::Microphone,
+            AudioVideo::Loudspeaker,
+            AudioVideo::Headphones,
+            AudioVideo::PortableAudio,
+            AudioVideo::CarAudio,
+            AudioVideo::SetTopBox,
+            AudioVideo::HiFiAudioDevice,
+            AudioVideo::Vcr,
+            AudioVideo::VideoCamera,
+            AudioVideo::Camcorder,
+            AudioVideo::VideoMonitor,
+            AudioVideo::VideoDisplayAndLoudspeaker,
+            AudioVideo::VideoConferencing,
+            AudioVideo::GamingToy,
+        ] {
+            assert_eq!(audio_video.to_string().parse(), Ok(audio_video));
+        }
+    }
+
+    #[test]
+    fn peripheral_display_from_str_round_trips() {
+        for peripheral in [
+            Peripheral::new(PeripheralUpper::Uncategorized, PeripheralLower::Uncategorized),
+            Peripheral::new(PeripheralUpper::Keyboard, PeripheralLower::Uncategorized),
+            Peripheral::new(PeripheralUpper::Uncategorized, PeripheralLower::Joystick),
+            Peripheral::new(PeripheralUpper::ComboKeyboardPointingDevice, PeripheralLower::Gamepad),
+        ] {
+            assert_eq!(peripheral.to_string().parse(), Ok(peripheral));
+        }
+    }
+
+    #[test]
+    fn imaging_display_from_str_round_trips() {
+        for imaging in [
+            Imaging::default(),
+            Imaging { display: true, ..Imaging::default() },
+            Imaging { camera: true, scanner: true, ..Imaging::default() },
+            Imaging { display: true, camera: true, scanner: true, printer: true },
+        ] {
+            assert_eq!(imaging.to_string().parse(), Ok(imaging));
+        }
+    }
+
+    #[test]
+    fn major_service_class_display_from_str_round_trips() {
+        for service_class in [
+            MajorServiceClass::empty(),
+            MajorServiceClass { audio: true, ..MajorServiceClass::empty() },
+            MajorServiceClass { networking: true, telephony: true, ..MajorServiceClass::empty() },
+        ] {
+            assert_eq!(service_class.to_string().parse(), Ok(service_class));
+        }
+    }
+
+    #[test]
+    fn device_class_kind_display_from_str_round_trips() {
+        for device_class in [
+            DeviceClassKind::Computer(Computer::Laptop),
+            DeviceClassKind::AudioVideo(AudioVideo::WearableHeadsetDevice),
+            DeviceClassKind::Peripheral(Peripheral::new(PeripheralUpper::Keyboard, PeripheralLower::Uncategorized)),
+        ] {
+            assert_eq!(device_class.to_string().parse(), Ok(device_class));
+        }
+    }
+
+    #[test]
+    fn class_of_device_builder_combines_service_and_device_class() {
+        let cod = ClassOfDevice::builder()
+            .service(MajorServiceClass { audio: true, ..MajorServiceClass::empty() })
+            .device(Computer::Laptop)
+            .build();
+
+        assert_eq!(cod.major_device_class(), 0x0100);
+        assert_eq!(cod.minor_device_class(), Computer::Laptop.minor_device_class());
+        assert_eq!(
+            cod.service_capabilities(),
+            MajorServiceClass { audio: true, ..MajorServiceClass::empty() },
+        );
+    }
+
+    #[test]
+    fn class_of_device_builder_defaults_to_miscellaneous_with_no_capabilities() {
+        let cod = ClassOfDevice::builder().build();
+
+        assert_eq!(cod.major_device_class(), 0x0000);
+        assert_eq!(cod.minor_device_class(), 0x00);
+        assert_eq!(cod.service_capabilities(), MajorServiceClass::empty());
+    }
+
+    #[test]
+    fn class_of_device_u32_round_trips_through_try_from_and_from() {
+        let cod = ClassOfDevice::builder()
+            .service(MajorServiceClass { networking: true, telephony: true, ..MajorServiceClass::empty() })
+            .device(Phone::Smartphone)
+            .build();
+
+        let raw: u32 = cod.into();
+        assert_eq!(ClassOfDevice::try_from(raw), Ok(cod));
+    }
+
+    #[test]
+    fn class_of_device_try_from_rejects_invalid_format_type() {
+        assert_eq!(
+            ClassOfDevice::try_from(0b01),
+            Err(ParseClassOfDeviceError::InvalidFormatType),
+        );
+    }
+}